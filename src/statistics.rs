@@ -17,6 +17,8 @@
 //! - **TempStatistics**: Accumulates data during active typing
 //! - **Statistics**: Final session summary with complete analysis
 //! - **CounterData**: Tracks various typing event counters
+//! - **LatencyStats**: Inter-keystroke rhythm analysis and hesitation detection
+//! - **Summary**: Box-plot-style distribution of WPM across a session
 //!
 //! ## Data Flow
 //!
@@ -35,18 +37,59 @@ use std::collections::HashMap;
 
 pub use web_time::{Duration, Instant};
 
+use serde::{Deserialize, Serialize};
+
 use crate::{
     CharacterResult, State, Timestamp, Word,
     config::Configuration,
     math::{Accuracy, Consistency, Ipm, Wpm},
 };
 
+/// Serializes a [`Duration`] as elapsed seconds (`f64`) instead of its
+/// platform-specific representation, so sessions can be persisted to disk
+/// (e.g. as JSON for a history/progress view) and read back portably.
+mod duration_as_secs {
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    use super::Duration;
+
+    pub fn serialize<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_f64(duration.as_secs_f64())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+        let secs = f64::deserialize(deserializer)?;
+        Duration::try_from_secs_f64(secs)
+            .map_err(|_| serde::de::Error::custom(format!("invalid duration: {secs} seconds")))
+    }
+}
+
+/// Validates a deserialized [`Timestamp`], rejecting negative, NaN, or
+/// infinite values — the same class of corrupted input `duration_as_secs`
+/// guards against, just for per-keystroke timestamps.
+mod validated_timestamp {
+    use serde::{Deserialize, Deserializer};
+
+    use super::Timestamp;
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Timestamp, D::Error> {
+        let timestamp = Timestamp::deserialize(deserializer)?;
+        if !timestamp.is_finite() || timestamp < 0.0 {
+            return Err(serde::de::Error::custom(format!(
+                "invalid timestamp: {timestamp} seconds"
+            )));
+        }
+        Ok(timestamp)
+    }
+}
+
 /// Individual keystroke event with timing and correctness information
 ///
 /// Used to build the complete history of typing activity for analysis.
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub struct Input {
     /// Timestamp in seconds from session start
+    #[serde(deserialize_with = "validated_timestamp::deserialize")]
     pub timestamp: Timestamp,
     /// Character that was typed
     pub char: char,
@@ -58,7 +101,7 @@ pub struct Input {
 ///
 /// Measurements are taken at regular intervals during typing to track
 /// performance changes over time and calculate consistency.
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub struct Measurement {
     /// When this measurement was taken (seconds from session start)
     pub timestamp: Timestamp,
@@ -131,7 +174,7 @@ impl Measurement {
 ///
 /// Tracks various statistics needed for performance analysis and detailed feedback.
 /// Used internally by TempStatistics to accumulate data during typing sessions.
-#[derive(Default, Debug, Clone)]
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
 pub struct CounterData {
     /// Number of errors for each character (for targeted practice)
     pub char_errors: HashMap<char, usize>,
@@ -151,11 +194,168 @@ pub struct CounterData {
     pub wrong_deletes: usize,
 }
 
+/// A single detected hesitation: an inter-keystroke gap far above the typist's norm
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Hesitation {
+    /// Character typed right after the pause
+    pub char: char,
+    /// Length of the pause, in seconds
+    pub latency: Timestamp,
+}
+
+/// Inter-keystroke rhythm analysis for a typing session
+///
+/// Summarizes the distribution of time gaps between consecutive keystrokes and
+/// flags unusually long gaps ("hesitations") using the standard IQR outlier rule.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct LatencyStats {
+    /// Median inter-keystroke interval (p50), in seconds
+    pub median: Timestamp,
+    /// 95th percentile interval, in seconds
+    pub p95: Timestamp,
+    /// 99th percentile interval, in seconds
+    pub p99: Timestamp,
+    /// First quartile (p25), in seconds
+    pub q1: Timestamp,
+    /// Third quartile (p75), in seconds
+    pub q3: Timestamp,
+    /// Intervals flagged as hesitations, in the order they occurred
+    pub hesitations: Vec<Hesitation>,
+}
+
+impl LatencyStats {
+    /// Compute rhythm statistics from a keystroke history
+    ///
+    /// Builds the vector of inter-keystroke intervals, skipping `Deleted`
+    /// events since they don't represent forward typing progress, then
+    /// summarizes the distribution with percentiles and flags every interval
+    /// above `Q3 + 1.5 * (Q3 - Q1)` as a hesitation.
+    ///
+    /// Returns a zeroed summary with no hesitations when fewer than two
+    /// keystrokes remain after filtering, since no interval can be formed.
+    pub fn from_history(history: &[Input]) -> Self {
+        let kept: Vec<&Input> = history
+            .iter()
+            .filter(|input| !matches!(input.result, CharacterResult::Deleted(_)))
+            .collect();
+
+        if kept.len() < 2 {
+            return Self::default();
+        }
+
+        let intervals: Vec<(Timestamp, char)> = kept
+            .windows(2)
+            .map(|pair| (pair[1].timestamp - pair[0].timestamp, pair[1].char))
+            .collect();
+
+        let mut sorted: Vec<Timestamp> = intervals.iter().map(|(interval, _)| *interval).collect();
+        sorted.sort_by(|a, b| a.total_cmp(b));
+
+        let q1 = interpolated_percentile(&sorted, 25.0);
+        let q3 = interpolated_percentile(&sorted, 75.0);
+        let median = interpolated_percentile(&sorted, 50.0);
+        let p95 = interpolated_percentile(&sorted, 95.0);
+        let p99 = interpolated_percentile(&sorted, 99.0);
+
+        let threshold = q3 + 1.5 * (q3 - q1);
+        let hesitations = intervals
+            .into_iter()
+            .filter(|(interval, _)| *interval > threshold)
+            .map(|(latency, char)| Hesitation { char, latency })
+            .collect();
+
+        Self {
+            median,
+            p95,
+            p99,
+            q1,
+            q3,
+            hesitations,
+        }
+    }
+}
+
+/// Linearly-interpolated percentile of an already-sorted slice
+///
+/// For a requested percentile `p` in `[0, 100]`, takes `rank = p/100 * (n-1)`
+/// and interpolates between `sorted[floor(rank)]` and `sorted[ceil(rank)]`.
+/// Returns `0.0` for an empty slice.
+fn interpolated_percentile(sorted: &[f64], p: f64) -> f64 {
+    let n = sorted.len();
+    if n == 0 {
+        return 0.0;
+    }
+
+    let rank = p / 100.0 * (n - 1) as f64;
+    let lo = rank.floor() as usize;
+    let hi = (lo + 1).min(n - 1);
+    let frac = rank - lo as f64;
+
+    sorted[lo] + frac * (sorted[hi] - sorted[lo])
+}
+
+/// Box-plot-style distribution summary of WPM across a session
+///
+/// Complements [`Consistency`], which reduces the whole session to a single
+/// std-dev-based number, by preserving the shape of the distribution:
+/// min, max, mean, median, and the quartiles.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Summary {
+    /// Slowest measurement's actual WPM
+    pub min: f64,
+    /// Fastest measurement's actual WPM
+    pub max: f64,
+    /// Arithmetic mean of actual WPM across all measurements
+    pub mean: f64,
+    /// Median (p50) actual WPM
+    pub median: f64,
+    /// First quartile (p25) actual WPM
+    pub q1: f64,
+    /// Third quartile (p75) actual WPM
+    pub q3: f64,
+    /// Inter-quartile range (`q3 - q1`)
+    pub iqr: f64,
+}
+
+impl Summary {
+    /// Build a WPM distribution summary from a session's measurements
+    ///
+    /// Copies each measurement's actual WPM into a Vec, sorts it, and derives
+    /// order statistics via [`interpolated_percentile`]. A session with a
+    /// single measurement returns that value for min, max, mean, median and
+    /// both quartiles, and an `iqr` of `0.0`.
+    pub fn from_measurements(measurements: &[Measurement]) -> Self {
+        if measurements.is_empty() {
+            return Self::default();
+        }
+
+        let mut values: Vec<f64> = measurements.iter().map(|m| m.wpm.actual).collect();
+        values.sort_by(|a, b| a.total_cmp(b));
+
+        let min = values[0];
+        let max = *values.last().unwrap();
+        let mean = values.iter().sum::<f64>() / values.len() as f64;
+        let median = interpolated_percentile(&values, 50.0);
+        let q1 = interpolated_percentile(&values, 25.0);
+        let q3 = interpolated_percentile(&values, 75.0);
+
+        Self {
+            min,
+            max,
+            mean,
+            median,
+            q1,
+            q3,
+            iqr: q3 - q1,
+        }
+    }
+}
+
 /// Complete statistical analysis of a finished typing session
 ///
 /// Contains final performance metrics, historical data, and detailed counters.
 /// Generated by finalizing a TempStatistics after the typing session ends.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Statistics {
     /// Final words per minute calculations (raw, corrected, actual)
     pub wpm: Wpm,
@@ -166,6 +366,7 @@ pub struct Statistics {
     /// Final consistency percentages and standard deviations
     pub consistency: Consistency,
     /// Total duration of the typing session
+    #[serde(with = "duration_as_secs")]
     pub duration: Duration,
 
     /// All measurements taken during the session (for trend analysis)
@@ -204,7 +405,81 @@ pub struct TempStatistics {
     last_measurement: Option<Timestamp>,
 }
 
+impl Statistics {
+    /// Build a practice session targeting the user's weakest words
+    ///
+    /// Collects every word with at least one recorded error, sorts them by
+    /// descending error frequency (ties broken alphabetically so the result is
+    /// deterministic), and repeats each one proportionally to how often it was
+    /// missed so the caller can immediately start a new test from it.
+    ///
+    /// # Parameters
+    ///
+    /// * `repeats_per_word` - Base number of repetitions per error; a word
+    ///   missed `n` times appears `repeats_per_word * n` times in the result
+    pub fn practice_set(&self, repeats_per_word: usize) -> Vec<Word> {
+        let mut words: Vec<(&Word, &usize)> = self
+            .counters
+            .word_errors
+            .iter()
+            .filter(|(_, &count)| count > 0)
+            .collect();
+
+        words.sort_by(|(word_a, count_a), (word_b, count_b)| {
+            count_b.cmp(count_a).then_with(|| word_a.cmp(word_b))
+        });
+
+        words
+            .into_iter()
+            .flat_map(|(word, &count)| std::iter::repeat_n(word.clone(), repeats_per_word * count))
+            .collect()
+    }
+}
+
 impl TempStatistics {
+    /// Reconstruct statistics by replaying a stored keystroke history
+    ///
+    /// Re-feeds each `Input` through [`TempStatistics::update`], the same
+    /// path used during live typing, so a session persisted to disk can be
+    /// reopened later for a history/progress view, and a stored keystroke
+    /// stream can be regression-tested to always reproduce identical
+    /// `Statistics`.
+    ///
+    /// The input length fed into each measurement is reconstructed from the
+    /// event stream itself: non-deleted events advance it by one, deleted
+    /// events retreat it by one, mirroring how the live caller tracks cursor
+    /// position.
+    ///
+    /// `Input::timestamp` is validated on deserialization, but `replay` also
+    /// guards against an invalid (negative, NaN, or infinite) timestamp
+    /// reaching it directly, treating it as `0.0` rather than panicking.
+    ///
+    /// # Parameters
+    ///
+    /// * `inputs` - The complete, ordered keystroke history to replay
+    /// * `config` - Configuration including measurement interval
+    pub fn replay(inputs: &[Input], config: &Configuration) -> Self {
+        let mut temp_stats = Self::default();
+        let mut input_len = 0usize;
+
+        for input in inputs {
+            match input.result {
+                CharacterResult::Deleted(_) => input_len = input_len.saturating_sub(1),
+                _ => input_len += 1,
+            }
+
+            temp_stats.update(
+                input.char,
+                input.result,
+                input_len,
+                Duration::try_from_secs_f64(input.timestamp).unwrap_or_default(),
+                config,
+            );
+        }
+
+        temp_stats
+    }
+
     /// Process a new keystroke event and update all statistics
     ///
     /// Updates counters, adds to input history, and takes a measurement
@@ -539,4 +814,313 @@ mod tests {
             "missing_characters should equal target length when nothing typed"
         );
     }
+
+    #[test]
+    fn test_practice_set_orders_by_error_count_and_weights_repeats() {
+        let temp_stats = TempStatistics::default();
+        let mut stats = temp_stats.finalize(Duration::from_secs(0), 0, 0);
+
+        stats.counters.word_errors.insert(Word::from("apple"), 1);
+        stats.counters.word_errors.insert(Word::from("banana"), 3);
+        stats.counters.word_errors.insert(Word::from("cherry"), 1);
+
+        let practice = stats.practice_set(2);
+
+        // banana (3 errors) comes first with 2*3 = 6 repeats, then apple/cherry
+        // (1 error each, 2 repeats) broken alphabetically
+        assert_eq!(
+            practice,
+            vec![
+                Word::from("banana"),
+                Word::from("banana"),
+                Word::from("banana"),
+                Word::from("banana"),
+                Word::from("banana"),
+                Word::from("banana"),
+                Word::from("apple"),
+                Word::from("apple"),
+                Word::from("cherry"),
+                Word::from("cherry"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_practice_set_empty_when_no_word_errors() {
+        let temp_stats = TempStatistics::default();
+        let stats = temp_stats.finalize(Duration::from_secs(0), 0, 0);
+
+        assert!(stats.practice_set(5).is_empty());
+    }
+
+    fn input(timestamp: Timestamp, char: char, result: CharacterResult) -> Input {
+        Input {
+            timestamp,
+            char,
+            result,
+        }
+    }
+
+    #[test]
+    fn test_latency_stats_empty_with_fewer_than_two_inputs() {
+        assert_eq!(LatencyStats::from_history(&[]), LatencyStats::default());
+
+        let single = [input(0.0, 'h', CharacterResult::Correct)];
+        assert_eq!(LatencyStats::from_history(&single), LatencyStats::default());
+    }
+
+    #[test]
+    fn test_latency_stats_skips_deleted_events() {
+        let history = [
+            input(0.0, 'h', CharacterResult::Correct),
+            input(1.0, 'x', CharacterResult::Wrong),
+            input(1.1, 'x', CharacterResult::Deleted(State::Wrong)),
+            input(2.0, 'e', CharacterResult::Corrected),
+        ];
+
+        // Deleted event is skipped, leaving intervals 'x'-'h' = 1.0s and 'e'-'x' = 1.0s
+        let stats = LatencyStats::from_history(&history);
+        assert_eq!(stats.median, 1.0);
+        assert_eq!(stats.hesitations.len(), 0);
+    }
+
+    #[test]
+    fn test_latency_stats_percentiles_and_hesitation_detection() {
+        // Evenly spaced keystrokes with one large pause before 'e'
+        let history = [
+            input(0.0, 'h', CharacterResult::Correct),
+            input(1.0, 'l', CharacterResult::Correct),
+            input(2.0, 'l', CharacterResult::Correct),
+            input(3.0, 'o', CharacterResult::Correct),
+            input(13.0, 'e', CharacterResult::Correct),
+        ];
+
+        let stats = LatencyStats::from_history(&history);
+
+        // Intervals: [1.0, 1.0, 1.0, 10.0]
+        assert_eq!(stats.q1, 1.0);
+        assert_eq!(stats.median, 1.0);
+        assert_eq!(stats.q3, 3.25);
+
+        assert_eq!(stats.hesitations.len(), 1);
+        assert_eq!(stats.hesitations[0].char, 'e');
+        assert_eq!(stats.hesitations[0].latency, 10.0);
+    }
+
+    fn measurement(wpm_actual: f64) -> Measurement {
+        Measurement {
+            timestamp: 0.0,
+            wpm: Wpm {
+                actual: wpm_actual,
+                ..Default::default()
+            },
+            ipm: Ipm::default(),
+            accuracy: Accuracy::default(),
+            consistency: Consistency::default(),
+        }
+    }
+
+    #[test]
+    fn test_summary_empty_when_no_measurements() {
+        assert_eq!(Summary::from_measurements(&[]), Summary::default());
+    }
+
+    #[test]
+    fn test_summary_single_measurement_returns_value_with_zero_iqr() {
+        let measurements = [measurement(42.0)];
+        let summary = Summary::from_measurements(&measurements);
+
+        assert_eq!(summary.min, 42.0);
+        assert_eq!(summary.max, 42.0);
+        assert_eq!(summary.mean, 42.0);
+        assert_eq!(summary.median, 42.0);
+        assert_eq!(summary.q1, 42.0);
+        assert_eq!(summary.q3, 42.0);
+        assert_eq!(summary.iqr, 0.0);
+    }
+
+    #[test]
+    fn test_summary_computes_quartiles_and_iqr() {
+        let measurements = [10.0, 20.0, 30.0, 40.0].map(measurement);
+        let summary = Summary::from_measurements(&measurements);
+
+        assert_eq!(summary.min, 10.0);
+        assert_eq!(summary.max, 40.0);
+        assert_eq!(summary.mean, 25.0);
+        assert_eq!(summary.median, 25.0);
+        assert_eq!(summary.q1, 17.5);
+        assert_eq!(summary.q3, 32.5);
+        assert_eq!(summary.iqr, 15.0);
+    }
+
+    #[test]
+    fn test_summary_does_not_panic_on_nan_wpm() {
+        // A zero-duration measurement (e.g. an instant/empty session) can
+        // produce a NaN WPM; sorting must not panic.
+        let measurements = [measurement(f64::NAN), measurement(10.0)];
+        let summary = Summary::from_measurements(&measurements);
+
+        assert!(summary.min.is_nan() || summary.max.is_nan());
+    }
+
+    #[test]
+    fn test_replay_reproduces_counters_and_history() {
+        let mut live = TempStatistics::default();
+        let config = Configuration::default();
+
+        live.update(
+            'h',
+            CharacterResult::Correct,
+            1,
+            Duration::from_secs(0),
+            &config,
+        );
+        live.update(
+            'e',
+            CharacterResult::Correct,
+            2,
+            Duration::from_secs(0),
+            &config,
+        );
+        live.update(
+            'x',
+            CharacterResult::Wrong,
+            3,
+            Duration::from_secs(0),
+            &config,
+        );
+        live.update(
+            'x',
+            CharacterResult::Deleted(State::Wrong),
+            2,
+            Duration::from_secs(0),
+            &config,
+        );
+        live.update(
+            'l',
+            CharacterResult::Corrected,
+            3,
+            Duration::from_secs(1),
+            &config,
+        );
+
+        let live_stats = live.clone().finalize(Duration::from_secs(1), 5, 3);
+
+        let replayed = TempStatistics::replay(&live_stats.input_history, &config);
+        let replayed_stats = replayed.finalize(Duration::from_secs(1), 5, 3);
+
+        assert_eq!(replayed_stats.counters.adds, live_stats.counters.adds);
+        assert_eq!(replayed_stats.counters.errors, live_stats.counters.errors);
+        assert_eq!(
+            replayed_stats.counters.corrections,
+            live_stats.counters.corrections
+        );
+        assert_eq!(
+            replayed_stats.measurements.len(),
+            live_stats.measurements.len()
+        );
+        assert_eq!(replayed_stats.input_history, live_stats.input_history);
+    }
+
+    #[test]
+    fn test_replay_of_empty_history_yields_default_statistics() {
+        let config = Configuration::default();
+        let replayed = TempStatistics::replay(&[], &config);
+
+        assert_eq!(replayed.input_history.len(), 0);
+        assert_eq!(replayed.counters.adds, 0);
+    }
+
+    #[test]
+    fn test_statistics_json_roundtrip_preserves_duration_as_seconds() {
+        let temp_stats = TempStatistics::default();
+        let stats = temp_stats.finalize(Duration::from_secs(42), 5, 5);
+
+        let json = serde_json::to_string(&stats).unwrap();
+        let restored: Statistics = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(restored.duration, stats.duration);
+        assert_eq!(restored.input_history, stats.input_history);
+    }
+
+    #[test]
+    fn test_statistics_json_roundtrip_preserves_word_and_char_errors() {
+        let temp_stats = TempStatistics::default();
+        let mut stats = temp_stats.finalize(Duration::from_secs(1), 0, 0);
+
+        stats.counters.word_errors.insert(Word::from("typo"), 2);
+        stats.counters.char_errors.insert('q', 3);
+
+        let json = serde_json::to_string(&stats).unwrap();
+        let restored: Statistics = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(restored.counters.word_errors, stats.counters.word_errors);
+        assert_eq!(restored.counters.char_errors, stats.counters.char_errors);
+    }
+
+    #[test]
+    fn test_statistics_json_rejects_invalid_duration() {
+        let temp_stats = TempStatistics::default();
+        let stats = temp_stats.finalize(Duration::from_secs(1), 0, 0);
+
+        let mut json: serde_json::Value =
+            serde_json::from_str(&serde_json::to_string(&stats).unwrap()).unwrap();
+        json["duration"] = serde_json::json!(-1.0);
+
+        let result: Result<Statistics, _> = serde_json::from_value(json);
+        assert!(
+            result.is_err(),
+            "negative duration should fail to deserialize"
+        );
+    }
+
+    #[test]
+    fn test_statistics_json_rejects_out_of_range_duration() {
+        let temp_stats = TempStatistics::default();
+        let stats = temp_stats.finalize(Duration::from_secs(1), 0, 0);
+
+        let mut json: serde_json::Value =
+            serde_json::from_str(&serde_json::to_string(&stats).unwrap()).unwrap();
+        json["duration"] = serde_json::json!(1e300);
+
+        let result: Result<Statistics, _> = serde_json::from_value(json);
+        assert!(
+            result.is_err(),
+            "a finite but out-of-range duration should fail to deserialize, not panic"
+        );
+    }
+
+    #[test]
+    fn test_input_json_rejects_invalid_timestamp() {
+        let input = Input {
+            timestamp: 1.0,
+            char: 'h',
+            result: CharacterResult::Correct,
+        };
+
+        let mut json: serde_json::Value =
+            serde_json::from_str(&serde_json::to_string(&input).unwrap()).unwrap();
+        json["timestamp"] = serde_json::json!(-1.0);
+
+        let result: Result<Input, _> = serde_json::from_value(json);
+        assert!(
+            result.is_err(),
+            "negative timestamp should fail to deserialize"
+        );
+    }
+
+    #[test]
+    fn test_replay_does_not_panic_on_invalid_timestamp() {
+        let config = Configuration::default();
+        let inputs = [Input {
+            timestamp: f64::NAN,
+            char: 'h',
+            result: CharacterResult::Correct,
+        }];
+
+        // Bypassing deserialization validation (e.g. a value built in-process)
+        // must still not panic; replay treats the bad timestamp as 0.0.
+        let replayed = TempStatistics::replay(&inputs, &config);
+        assert_eq!(replayed.input_history.len(), 1);
+    }
 }